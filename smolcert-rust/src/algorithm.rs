@@ -0,0 +1,108 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde::{Serialize, Serializer};
+
+use crate::errors::{Error, ErrorCode};
+
+/// Signature algorithm identifier carried as a small CBOR-encoded integer in
+/// every certificate, naming the scheme of that certificate's own
+/// `public_key`; a certificate's signature is checked under its issuer's
+/// algorithm. New schemes can be added over time without breaking decoding
+/// of certs that use an existing one.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Algorithm {
+  Ed25519,
+}
+
+impl Algorithm {
+  fn id(self) -> u8 {
+    match self {
+      Algorithm::Ed25519 => 0,
+    }
+  }
+
+  /// Decoded separately from serde so an unknown id fails with
+  /// `ErrorCode::UnsupportedAlgorithm`, not an opaque serde error — see
+  /// `Certificate::from_slice`, which is the only place this runs.
+  pub(crate) fn from_id(id: u8) -> crate::errors::Result<Algorithm> {
+    match id {
+      0 => Ok(Algorithm::Ed25519),
+      other => Err(Error {
+        code: ErrorCode::UnsupportedAlgorithm(other),
+      }),
+    }
+  }
+}
+
+impl Serialize for Algorithm {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(self.id())
+  }
+}
+
+/// Verifies a signature under a particular algorithm. Each signature scheme
+/// smolcert supports implements this, so verification dispatches through the
+/// trait instead of hard-coding ed25519. The trait has no signing method;
+/// signing is not done through it.
+pub trait SignatureScheme {
+  fn verify(&self, message: &[u8], signature: &[u8], public_key: &[u8]) -> crate::errors::Result<()>;
+}
+
+pub struct Ed25519Scheme;
+
+impl SignatureScheme for Ed25519Scheme {
+  fn verify(&self, message: &[u8], signature: &[u8], public_key: &[u8]) -> crate::errors::Result<()> {
+    let key = PublicKey::from_bytes(public_key)?;
+    let sig = Signature::from_bytes(signature)?;
+    key.verify(message, &sig)?;
+    Ok(())
+  }
+}
+
+pub(crate) fn scheme_for(algorithm: Algorithm) -> Box<dyn SignatureScheme> {
+  match algorithm {
+    Algorithm::Ed25519 => Box::new(Ed25519Scheme),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ed25519_dalek::{Keypair, Signer};
+  use rand::rngs::OsRng;
+
+  #[test]
+  fn from_id_recognizes_ed25519() {
+    assert_eq!(Algorithm::from_id(0).unwrap(), Algorithm::Ed25519);
+  }
+
+  #[test]
+  fn from_id_rejects_an_unknown_id() {
+    let err = Algorithm::from_id(99).unwrap_err();
+    assert!(matches!(err.code(), ErrorCode::UnsupportedAlgorithm(99)));
+  }
+
+  #[test]
+  fn ed25519_scheme_verifies_a_genuine_signature() {
+    let mut csprng = OsRng {};
+    let keypair = Keypair::generate(&mut csprng);
+    let message = b"smolcert body";
+    let signature = keypair.sign(message).to_bytes();
+
+    let scheme = scheme_for(Algorithm::Ed25519);
+    assert!(scheme
+      .verify(message, &signature, &keypair.public.to_bytes())
+      .is_ok());
+  }
+
+  #[test]
+  fn ed25519_scheme_rejects_a_tampered_message() {
+    let mut csprng = OsRng {};
+    let keypair = Keypair::generate(&mut csprng);
+    let signature = keypair.sign(b"smolcert body").to_bytes();
+
+    let scheme = scheme_for(Algorithm::Ed25519);
+    assert!(scheme
+      .verify(b"a different body", &signature, &keypair.public.to_bytes())
+      .is_err());
+  }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::algorithm::Algorithm;
+use crate::errors::Result;
+use crate::sct::Sct;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Certificate {
+  pub version: u8,
+  pub algorithm: Algorithm,
+  pub issuer: String,
+  pub subject: String,
+  pub not_before: u64,
+  pub not_after: u64,
+  pub public_key: [u8; 32],
+  #[serde(default)]
+  pub scts: Vec<Sct>,
+  pub signature: Vec<u8>,
+}
+
+/// Wire-identical to `Certificate`, except `algorithm` is the raw id so it
+/// can be converted through `Algorithm::from_id` and fail with
+/// `ErrorCode::UnsupportedAlgorithm` instead of an opaque serde error.
+#[derive(Deserialize)]
+struct RawCertificate {
+  version: u8,
+  algorithm: u8,
+  issuer: String,
+  subject: String,
+  not_before: u64,
+  not_after: u64,
+  public_key: [u8; 32],
+  #[serde(default)]
+  scts: Vec<Sct>,
+  signature: Vec<u8>,
+}
+
+impl Certificate {
+  /// Decodes a certificate from its CBOR encoding.
+  pub fn from_slice(bytes: &[u8]) -> Result<Certificate> {
+    let raw: RawCertificate = serde_cbor::from_slice(bytes)?;
+    Ok(Certificate {
+      version: raw.version,
+      algorithm: Algorithm::from_id(raw.algorithm)?,
+      issuer: raw.issuer,
+      subject: raw.subject,
+      not_before: raw.not_before,
+      not_after: raw.not_after,
+      public_key: raw.public_key,
+      scts: raw.scts,
+      signature: raw.signature,
+    })
+  }
+
+  /// Returns the CBOR encoding of everything in this certificate that is
+  /// covered by its signature, i.e. everything but the signature and the
+  /// SCTs.
+  pub(crate) fn body_bytes(&self) -> Result<Vec<u8>> {
+    #[derive(Serialize)]
+    struct Body<'a> {
+      version: u8,
+      algorithm: Algorithm,
+      issuer: &'a str,
+      subject: &'a str,
+      not_before: u64,
+      not_after: u64,
+      public_key: &'a [u8; 32],
+    }
+
+    let body = Body {
+      version: self.version,
+      algorithm: self.algorithm,
+      issuer: &self.issuer,
+      subject: &self.subject,
+      not_before: self.not_before,
+      not_after: self.not_after,
+      public_key: &self.public_key,
+    };
+    Ok(serde_cbor::to_vec(&body)?)
+  }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+
+use crate::algorithm;
+use crate::cert::Certificate;
+use crate::errors::{Error, ErrorCode, Result, ValidationErrorCode};
+use crate::keyring::Keyring;
+use crate::sct::TransparencyLog;
+
+/// Maximum number of links walked from leaf to trust anchor, bounding work on
+/// adversarial chains that are arbitrarily long or loop back on themselves.
+const MAX_CHAIN_DEPTH: usize = 16;
+
+/// Walks from `leaf` through `intermediates` to a trust anchor in `roots`,
+/// verifying each link's signature and that its validity window covers
+/// `verification_time_ms`, and that `leaf` carries at least
+/// `required_scts` valid SCTs from distinct logs in `transparency_log`
+/// (pass `0` to skip the transparency-log check entirely). Returns
+/// `Untrusted` only when no such path exists, and `ChainTooLong` if the
+/// walk exceeds `MAX_CHAIN_DEPTH` or revisits a certificate it has already
+/// seen; both name the certificate where the walk stopped.
+pub fn validate_chain(
+  leaf: &Certificate,
+  intermediates: &[Certificate],
+  roots: &Keyring,
+  transparency_log: &TransparencyLog,
+  required_scts: usize,
+  verification_time_ms: u64,
+) -> Result<()> {
+  if required_scts > 0 {
+    transparency_log.verify(leaf, required_scts)?;
+  }
+
+  let mut current = leaf;
+  let mut visited = HashSet::new();
+  visited.insert(current.subject.clone());
+
+  for _ in 0..MAX_CHAIN_DEPTH {
+    check_validity(current, verification_time_ms)?;
+
+    if roots.verify(current).is_ok() {
+      return Ok(());
+    }
+
+    let parent = intermediates
+      .iter()
+      .find(|candidate| candidate.subject == current.issuer)
+      .ok_or_else(|| Error {
+        code: ErrorCode::ValidationError(ValidationErrorCode::Untrusted {
+          subject: current.subject.clone(),
+        }),
+      })?;
+
+    if !visited.insert(parent.subject.clone()) {
+      return Err(Error {
+        code: ErrorCode::ValidationError(ValidationErrorCode::ChainTooLong {
+          subject: parent.subject.clone(),
+        }),
+      });
+    }
+
+    verify_link(current, parent)?;
+    current = parent;
+  }
+
+  Err(Error {
+    code: ErrorCode::ValidationError(ValidationErrorCode::ChainTooLong {
+      subject: current.subject.clone(),
+    }),
+  })
+}
+
+fn check_validity(cert: &Certificate, verification_time_ms: u64) -> Result<()> {
+  if verification_time_ms < cert.not_before || verification_time_ms > cert.not_after {
+    return Err(Error {
+      code: ErrorCode::ValidationError(ValidationErrorCode::ValidityError {
+        not_before: cert.not_before,
+        not_after: cert.not_after,
+      }),
+    });
+  }
+  Ok(())
+}
+
+fn verify_link(child: &Certificate, parent: &Certificate) -> Result<()> {
+  let body = child.body_bytes()?;
+  let scheme = algorithm::scheme_for(parent.algorithm);
+  scheme.verify(&body, &child.signature, &parent.public_key).map_err(|_| Error {
+    code: ErrorCode::ValidationError(ValidationErrorCode::Untrusted {
+      subject: child.subject.clone(),
+    }),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::algorithm::Algorithm;
+  use ed25519_dalek::{Keypair, Signer};
+  use rand::rngs::OsRng;
+
+  fn cert(subject: &str, issuer: &str, not_before: u64, not_after: u64, public_key: [u8; 32], signer: &Keypair) -> Certificate {
+    let mut cert = Certificate {
+      version: 1,
+      algorithm: Algorithm::Ed25519,
+      issuer: issuer.into(),
+      subject: subject.into(),
+      not_before,
+      not_after,
+      public_key,
+      scts: Vec::new(),
+      signature: Vec::new(),
+    };
+    let body = cert.body_bytes().unwrap();
+    cert.signature = signer.sign(&body).to_bytes().to_vec();
+    cert
+  }
+
+  #[test]
+  fn validate_chain_accepts_a_leaf_through_an_intermediate_to_a_root() {
+    let mut csprng = OsRng {};
+    let root_key = Keypair::generate(&mut csprng);
+    let intermediate_key = Keypair::generate(&mut csprng);
+    let leaf_key = Keypair::generate(&mut csprng);
+
+    let root = cert("root", "root", 0, u64::MAX, root_key.public.to_bytes(), &root_key);
+    let intermediate = cert("intermediate", "root", 0, u64::MAX, intermediate_key.public.to_bytes(), &root_key);
+    let leaf = cert("leaf", "intermediate", 0, u64::MAX, leaf_key.public.to_bytes(), &intermediate_key);
+
+    let mut roots = Keyring::new();
+    roots.add("root".into(), Algorithm::Ed25519, root.public_key.to_vec());
+
+    assert!(validate_chain(&leaf, &[intermediate], &roots, &TransparencyLog::new(), 0, 0).is_ok());
+  }
+
+  #[test]
+  fn validate_chain_rejects_expired_certificates() {
+    let mut csprng = OsRng {};
+    let root_key = Keypair::generate(&mut csprng);
+    let leaf = cert("leaf", "root", 0, 100, root_key.public.to_bytes(), &root_key);
+
+    let mut roots = Keyring::new();
+    roots.add("root".into(), Algorithm::Ed25519, root_key.public.to_bytes().to_vec());
+
+    let err = validate_chain(&leaf, &[], &roots, &TransparencyLog::new(), 0, 200).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::ValidityError { .. })
+    ));
+  }
+
+  #[test]
+  fn validate_chain_detects_a_cycle() {
+    let mut csprng = OsRng {};
+    let leaf_key = Keypair::generate(&mut csprng);
+    let mid_key = Keypair::generate(&mut csprng);
+
+    let leaf = cert("leaf", "mid", 0, u64::MAX, leaf_key.public.to_bytes(), &mid_key);
+    let mid = cert("mid", "leaf", 0, u64::MAX, mid_key.public.to_bytes(), &leaf_key);
+    // Only `subject` is inspected once the cycle is detected, so this stand-in
+    // for "leaf" reappearing as its own ancestor doesn't need a real signature.
+    let leaf_again = cert("leaf", "mid", 0, u64::MAX, leaf_key.public.to_bytes(), &mid_key);
+
+    let roots = Keyring::new();
+
+    let err = validate_chain(&leaf, &[mid, leaf_again], &roots, &TransparencyLog::new(), 0, 0).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::ChainTooLong { .. })
+    ));
+  }
+
+  #[test]
+  fn validate_chain_bounds_work_on_an_overlong_chain() {
+    let mut csprng = OsRng {};
+    let keypairs: Vec<Keypair> = (0..20).map(|_| Keypair::generate(&mut csprng)).collect();
+    let names: Vec<String> = (0..keypairs.len()).map(|i| format!("c{}", i)).collect();
+
+    let certs: Vec<Certificate> = (0..names.len() - 1)
+      .map(|i| {
+        cert(
+          &names[i],
+          &names[i + 1],
+          0,
+          u64::MAX,
+          keypairs[i].public.to_bytes(),
+          &keypairs[i + 1],
+        )
+      })
+      .collect();
+
+    let leaf = certs[0].clone();
+    let intermediates = certs[1..].to_vec();
+    let roots = Keyring::new();
+
+    let err = validate_chain(&leaf, &intermediates, &roots, &TransparencyLog::new(), 0, 0).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::ChainTooLong { .. })
+    ));
+  }
+}
@@ -1,16 +1,45 @@
 use ed25519_dalek::{SignatureError};
 use serde_cbor::error::Error as SerdeError;
 use std::time::SystemTimeError;
+use thiserror::Error as ThisError;
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug, ThisError)]
 pub enum ValidationErrorCode {
+  #[error("certificate signature is invalid")]
   SignatureError,
+  #[error("certificate is not valid at the requested time (not_before={not_before}, not_after={not_after})")]
   ValidityError{
     not_before: u64,
     not_after: u64,
   },
+  #[error("failed to read system time")]
   TimeError,
-  Untrusted,
+  #[error("certificate \"{subject}\" is not trusted")]
+  Untrusted {
+    subject: String,
+  },
+  #[error("certificate does not carry enough valid signed certificate timestamps")]
+  SctError,
+  #[error("no key enrolled in the keyring for this certificate's issuer")]
+  KeyNotFound,
+  #[error("certificate chain through \"{subject}\" exceeds the maximum depth or contains a cycle")]
+  ChainTooLong {
+    subject: String,
+  },
+}
+
+#[derive(Debug, ThisError)]
+pub enum ErrorCode {
+  #[error("failed to (de)serialize certificate: {0}")]
+  Serialization(SerdeError),
+  #[error("signature error: {0}")]
+  Signature(SignatureError),
+  #[error("{0}")]
+  ValidationError(ValidationErrorCode),
+  #[error("certificate uses unsupported signature algorithm {0}")]
+  UnsupportedAlgorithm(u8),
 }
 
 #[derive(Debug)]
@@ -18,11 +47,28 @@ pub struct Error {
   pub(crate) code: ErrorCode,
 }
 
-#[derive(Debug)]
-pub enum ErrorCode {
-  Serialization(SerdeError),
-  Signature(SignatureError),
-  ValidationError(ValidationErrorCode)
+impl Error {
+  /// The structured reason validation failed, for callers that want to
+  /// match on it rather than parse the `Display` message.
+  pub fn code(&self) -> &ErrorCode {
+    &self.code
+  }
+}
+
+impl std::fmt::Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.code)
+  }
+}
+
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match &self.code {
+      ErrorCode::Serialization(err) => Some(err),
+      ErrorCode::Signature(err) => Some(err),
+      ErrorCode::ValidationError(_) | ErrorCode::UnsupportedAlgorithm(_) => None,
+    }
+  }
 }
 
 impl From<SystemTimeError> for Error {
@@ -47,4 +93,55 @@ impl From<SignatureError> for Error {
       code: ErrorCode::Signature(err),
     }
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::error::Error as StdError;
+
+  #[test]
+  fn validity_error_message_includes_the_window() {
+    let code = ValidationErrorCode::ValidityError {
+      not_before: 10,
+      not_after: 20,
+    };
+    assert_eq!(
+      code.to_string(),
+      "certificate is not valid at the requested time (not_before=10, not_after=20)"
+    );
+  }
+
+  #[test]
+  fn code_returns_the_structured_reason() {
+    let err = Error {
+      code: ErrorCode::ValidationError(ValidationErrorCode::KeyNotFound),
+    };
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::KeyNotFound)
+    ));
+  }
+
+  #[test]
+  fn source_chains_through_to_the_underlying_serde_error() {
+    let serde_err = serde_cbor::from_slice::<u8>(&[]).unwrap_err();
+    let err: Error = serde_err.into();
+    assert!(err.source().is_some());
+  }
+
+  #[test]
+  fn source_chains_through_to_the_underlying_signature_error() {
+    let sig_err = ed25519_dalek::Signature::from_bytes(&[0u8; 10]).unwrap_err();
+    let err: Error = sig_err.into();
+    assert!(err.source().is_some());
+  }
+
+  #[test]
+  fn source_is_none_for_validation_errors() {
+    let err = Error {
+      code: ErrorCode::ValidationError(ValidationErrorCode::KeyNotFound),
+    };
+    assert!(err.source().is_none());
+  }
+}
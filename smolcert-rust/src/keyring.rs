@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::algorithm::{self, Algorithm};
+use crate::cert::Certificate;
+use crate::errors::{Error, ErrorCode, Result, ValidationErrorCode};
+
+/// A set of trusted verification keys indexed by key-id, used in place of a
+/// single hard-coded root so callers can manage a rotating set of signers.
+/// Each root carries its own `Algorithm`, since a verifier's roots can mix
+/// signature schemes even when a given certificate only uses one.
+#[derive(Default)]
+pub struct Keyring {
+  keys: HashMap<String, (Algorithm, Vec<u8>)>,
+}
+
+impl Keyring {
+  pub fn new() -> Keyring {
+    Keyring { keys: HashMap::new() }
+  }
+
+  pub fn add(&mut self, key_id: String, algorithm: Algorithm, key: Vec<u8>) {
+    self.keys.insert(key_id, (algorithm, key));
+  }
+
+  pub fn remove(&mut self, key_id: &str) -> Option<(Algorithm, Vec<u8>)> {
+    self.keys.remove(key_id)
+  }
+
+  /// Verifies `cert`'s signature against the key matching its issuer, under
+  /// that key's own algorithm (not `cert.algorithm`, which only describes
+  /// the certificate's own subject key). Returns `KeyNotFound` if no key is
+  /// enrolled for that issuer, and `Untrusted` if the enrolled key does not
+  /// validate the signature.
+  pub fn verify(&self, cert: &Certificate) -> Result<()> {
+    let (algorithm, key) = self.keys.get(&cert.issuer).ok_or_else(|| Error {
+      code: ErrorCode::ValidationError(ValidationErrorCode::KeyNotFound),
+    })?;
+    let body = cert.body_bytes()?;
+    let scheme = algorithm::scheme_for(*algorithm);
+    scheme.verify(&body, &cert.signature, key).map_err(|_| Error {
+      code: ErrorCode::ValidationError(ValidationErrorCode::Untrusted {
+        subject: cert.subject.clone(),
+      }),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ed25519_dalek::{Keypair, Signer};
+  use rand::rngs::OsRng;
+
+  fn signed_cert(issuer: &str, keypair: &Keypair) -> Certificate {
+    let mut cert = Certificate {
+      version: 1,
+      algorithm: Algorithm::Ed25519,
+      issuer: issuer.into(),
+      subject: "leaf".into(),
+      not_before: 0,
+      not_after: u64::MAX,
+      public_key: [0u8; 32],
+      scts: Vec::new(),
+      signature: Vec::new(),
+    };
+    let body = cert.body_bytes().unwrap();
+    cert.signature = keypair.sign(&body).to_bytes().to_vec();
+    cert
+  }
+
+  #[test]
+  fn verify_accepts_a_cert_signed_by_an_enrolled_root() {
+    let mut csprng = OsRng {};
+    let root = Keypair::generate(&mut csprng);
+    let cert = signed_cert("root", &root);
+
+    let mut keyring = Keyring::new();
+    keyring.add("root".into(), Algorithm::Ed25519, root.public.to_bytes().to_vec());
+
+    assert!(keyring.verify(&cert).is_ok());
+  }
+
+  #[test]
+  fn verify_fails_with_key_not_found_for_an_unknown_issuer() {
+    let mut csprng = OsRng {};
+    let root = Keypair::generate(&mut csprng);
+    let cert = signed_cert("unknown-root", &root);
+
+    let keyring = Keyring::new();
+
+    let err = keyring.verify(&cert).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::KeyNotFound)
+    ));
+  }
+
+  #[test]
+  fn verify_fails_with_untrusted_for_a_wrong_signature() {
+    let mut csprng = OsRng {};
+    let root = Keypair::generate(&mut csprng);
+    let impostor = Keypair::generate(&mut csprng);
+    let cert = signed_cert("root", &impostor);
+
+    let mut keyring = Keyring::new();
+    keyring.add("root".into(), Algorithm::Ed25519, root.public.to_bytes().to_vec());
+
+    let err = keyring.verify(&cert).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::Untrusted { .. })
+    ));
+  }
+}
@@ -0,0 +1,13 @@
+mod algorithm;
+mod cert;
+mod chain;
+mod errors;
+mod keyring;
+mod sct;
+
+pub use algorithm::Algorithm;
+pub use cert::Certificate;
+pub use chain::validate_chain;
+pub use errors::{Error, ErrorCode, Result, ValidationErrorCode};
+pub use keyring::Keyring;
+pub use sct::{Sct, TransparencyLog};
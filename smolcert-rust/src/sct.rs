@@ -0,0 +1,163 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::cert::Certificate;
+use crate::errors::{Error, ErrorCode, Result, ValidationErrorCode};
+
+/// signature_type for a certificate_timestamp, per RFC 6962 section 3.2.
+const SIGNATURE_TYPE_CERTIFICATE_TIMESTAMP: u8 = 0;
+/// entry_type for the (sole) smolcert leaf type, per RFC 6962 section 3.2.
+const ENTRY_TYPE_SMOLCERT: u16 = 0;
+
+/// A transparency log's promise that it logged a certificate, reconstructed
+/// and verified as in RFC 6962 section 3.2.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Sct {
+  pub log_key_id: [u8; 32],
+  pub timestamp: u64,
+  pub signature: Vec<u8>,
+}
+
+impl Sct {
+  fn signed_data(&self, version: u8, cert_body: &[u8]) -> Result<Vec<u8>> {
+    #[derive(Serialize)]
+    struct SignedCertificateTimestamp<'a> {
+      version: u8,
+      signature_type: u8,
+      timestamp: u64,
+      entry_type: u16,
+      cert_body: &'a [u8],
+    }
+
+    let signed = SignedCertificateTimestamp {
+      version,
+      signature_type: SIGNATURE_TYPE_CERTIFICATE_TIMESTAMP,
+      timestamp: self.timestamp,
+      entry_type: ENTRY_TYPE_SMOLCERT,
+      cert_body,
+    };
+    Ok(serde_cbor::to_vec(&signed)?)
+  }
+
+  fn verify(&self, cert: &Certificate, log_key: &PublicKey) -> Result<()> {
+    let signature = Signature::from_bytes(&self.signature)?;
+    let body = cert.body_bytes()?;
+    let signed_data = self.signed_data(cert.version, &body)?;
+    log_key.verify(&signed_data, &signature)?;
+    Ok(())
+  }
+}
+
+/// A registry of transparency logs a verifier is willing to accept SCTs from,
+/// mapping each log's key-id (SHA-256 of its operator's SPKI) to its key.
+#[derive(Default)]
+pub struct TransparencyLog {
+  logs: HashMap<[u8; 32], PublicKey>,
+}
+
+impl TransparencyLog {
+  pub fn new() -> TransparencyLog {
+    TransparencyLog { logs: HashMap::new() }
+  }
+
+  pub fn add(&mut self, key_id: [u8; 32], key: PublicKey) {
+    self.logs.insert(key_id, key);
+  }
+
+  pub fn remove(&mut self, key_id: &[u8; 32]) -> Option<PublicKey> {
+    self.logs.remove(key_id)
+  }
+
+  /// Verifies that `cert` carries at least `required` valid SCTs from
+  /// distinct logs known to this registry, failing with `SctError` if it
+  /// does not.
+  pub fn verify(&self, cert: &Certificate, required: usize) -> Result<()> {
+    let mut distinct_logs = HashSet::new();
+    for sct in &cert.scts {
+      let log_key = match self.logs.get(&sct.log_key_id) {
+        Some(key) => key,
+        None => continue,
+      };
+      if sct.verify(cert, log_key).is_ok() {
+        distinct_logs.insert(sct.log_key_id);
+      }
+    }
+
+    if distinct_logs.len() >= required {
+      Ok(())
+    } else {
+      Err(Error {
+        code: ErrorCode::ValidationError(ValidationErrorCode::SctError),
+      })
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ed25519_dalek::{Keypair, Signer};
+  use rand::rngs::OsRng;
+
+  fn test_cert() -> Certificate {
+    Certificate {
+      version: 1,
+      algorithm: crate::algorithm::Algorithm::Ed25519,
+      issuer: "root".into(),
+      subject: "leaf".into(),
+      not_before: 0,
+      not_after: u64::MAX,
+      public_key: [0u8; 32],
+      scts: Vec::new(),
+      signature: Vec::new(),
+    }
+  }
+
+  fn signed_sct(cert: &Certificate, log_key_id: [u8; 32], log_keypair: &Keypair, timestamp: u64) -> Sct {
+    let mut sct = Sct {
+      log_key_id,
+      timestamp,
+      signature: Vec::new(),
+    };
+    let body = cert.body_bytes().unwrap();
+    let signed_data = sct.signed_data(cert.version, &body).unwrap();
+    sct.signature = log_keypair.sign(&signed_data).to_bytes().to_vec();
+    sct
+  }
+
+  #[test]
+  fn verify_accepts_a_valid_sct_from_a_known_log() {
+    let mut csprng = OsRng {};
+    let log_keypair = Keypair::generate(&mut csprng);
+    let log_key_id = [1u8; 32];
+
+    let mut cert = test_cert();
+    cert.scts.push(signed_sct(&cert, log_key_id, &log_keypair, 42));
+
+    let mut log = TransparencyLog::new();
+    log.add(log_key_id, log_keypair.public);
+
+    assert!(log.verify(&cert, 1).is_ok());
+  }
+
+  #[test]
+  fn verify_rejects_an_sct_not_signed_by_the_claimed_log() {
+    let mut csprng = OsRng {};
+    let log_keypair = Keypair::generate(&mut csprng);
+    let impostor_keypair = Keypair::generate(&mut csprng);
+    let log_key_id = [2u8; 32];
+
+    let mut cert = test_cert();
+    cert.scts.push(signed_sct(&cert, log_key_id, &impostor_keypair, 42));
+
+    let mut log = TransparencyLog::new();
+    log.add(log_key_id, log_keypair.public);
+
+    let err = log.verify(&cert, 1).unwrap_err();
+    assert!(matches!(
+      err.code(),
+      ErrorCode::ValidationError(ValidationErrorCode::SctError)
+    ));
+  }
+}